@@ -0,0 +1,169 @@
+package collections
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"errors"
+	"fmt"
+
+	"cosmossdk.io/collections/codec"
+)
+
+// ErrChunkedItemCorrupted is returned when the chunks of a ChunkedItem do not
+// match the checksum recorded when the value was set.
+var ErrChunkedItemCorrupted = errors.New("collections: chunked item is corrupted")
+
+const (
+	ChunkedItemChunksNameSuffix     = "_chunks"
+	ChunkedItemLengthNameSuffix     = "_length"
+	ChunkedItemChecksumNameSuffix   = "_checksum"
+	ChunkedItemChunksPrefixSuffix   = 0x0
+	ChunkedItemLengthPrefixSuffix   = 0x1
+	ChunkedItemChecksumPrefixSuffix = 0x2
+)
+
+// NewChunkedItem creates a new ChunkedItem instance, which stores the encoded value
+// split in chunks of at most chunkSize bytes. It panics if chunkSize is not positive.
+// Since ChunkedItem relies on three collections, it will register three state objects
+// on the schema builder: a map holding the chunks, an item holding the number of chunks
+// and an item holding the checksum of the encoded value. Their prefixes are the provided
+// prefix suffixed respectively with ChunkedItemChunksPrefixSuffix, ChunkedItemLengthPrefixSuffix
+// and ChunkedItemChecksumPrefixSuffix, their names are suffixed with ChunkedItemChunksNameSuffix,
+// ChunkedItemLengthNameSuffix and ChunkedItemChecksumNameSuffix.
+func NewChunkedItem[V any](sb *SchemaBuilder, prefix Prefix, name string, vc codec.ValueCodec[V], chunkSize int) ChunkedItem[V] {
+	if chunkSize <= 0 {
+		panic(fmt.Errorf("invalid chunk size %d for chunked item %s", chunkSize, name))
+	}
+	return ChunkedItem[V]{
+		vc:        vc,
+		chunkSize: chunkSize,
+		chunks:    NewMap(sb, append(prefix, ChunkedItemChunksPrefixSuffix), name+ChunkedItemChunksNameSuffix, Uint64Key, BytesValue),
+		length:    NewItem(sb, append(prefix, ChunkedItemLengthPrefixSuffix), name+ChunkedItemLengthNameSuffix, Uint64Value),
+		checksum:  NewItem(sb, append(prefix, ChunkedItemChecksumPrefixSuffix), name+ChunkedItemChecksumNameSuffix, BytesValue),
+	}
+}
+
+// ChunkedItem works like an Item whose encoded value can be larger than what a
+// single store entry should hold, like serialized order books or code blobs.
+// The encoded value is split across multiple keys of a Map[uint64, []byte], and a
+// sha256 checksum of the whole value is verified every time the value is read.
+type ChunkedItem[V any] struct {
+	vc        codec.ValueCodec[V]
+	chunkSize int
+
+	chunks   Map[uint64, []byte]
+	length   Item[uint64]
+	checksum Item[[]byte]
+}
+
+// Get gets the value, if it is not set it returns an ErrNotFound error.
+// If the checksum or any chunk is missing, or the chunks do not match the
+// recorded checksum, ErrChunkedItemCorrupted is returned. If value decoding
+// fails then an ErrEncoding is returned.
+func (c ChunkedItem[V]) Get(ctx context.Context) (v V, err error) {
+	length, err := c.length.Get(ctx)
+	if err != nil {
+		return v, err
+	}
+	checksum, err := c.checksum.Get(ctx)
+	switch {
+	case err == nil:
+	case errors.Is(err, ErrNotFound):
+		return v, fmt.Errorf("%w: missing checksum", ErrChunkedItemCorrupted)
+	default:
+		return v, err
+	}
+
+	var buf []byte
+	for i := uint64(0); i < length; i++ {
+		var chunk []byte
+		chunk, err = c.chunks.Get(ctx, i)
+		switch {
+		case err == nil:
+			buf = append(buf, chunk...)
+		case errors.Is(err, ErrNotFound):
+			return v, fmt.Errorf("%w: missing chunk %d of %d", ErrChunkedItemCorrupted, i, length)
+		default:
+			return v, err
+		}
+	}
+
+	digest := sha256.Sum256(buf)
+	if !bytes.Equal(digest[:], checksum) {
+		return v, fmt.Errorf("%w: checksum mismatch", ErrChunkedItemCorrupted)
+	}
+
+	v, err = c.vc.Decode(buf)
+	if err != nil {
+		return v, fmt.Errorf("%w: value decode: %w", ErrEncoding, err)
+	}
+	return v, nil
+}
+
+// Set encodes the value and stores it in chunks, removing any chunk left over
+// from a previous larger value. If value encoding fails then an ErrEncoding is returned.
+func (c ChunkedItem[V]) Set(ctx context.Context, value V) error {
+	bz, err := c.vc.Encode(value)
+	if err != nil {
+		return fmt.Errorf("%w: value encode: %w", ErrEncoding, err)
+	}
+
+	oldLength, err := c.length.Get(ctx)
+	if err != nil && !errors.Is(err, ErrNotFound) {
+		return err
+	}
+
+	length := uint64(0)
+	for start := 0; start < len(bz); start += c.chunkSize {
+		end := min(start+c.chunkSize, len(bz))
+		err = c.chunks.Set(ctx, length, bz[start:end])
+		if err != nil {
+			return err
+		}
+		length++
+	}
+	for i := length; i < oldLength; i++ {
+		err = c.chunks.Remove(ctx, i)
+		if err != nil {
+			return err
+		}
+	}
+
+	digest := sha256.Sum256(bz)
+	err = c.checksum.Set(ctx, digest[:])
+	if err != nil {
+		return err
+	}
+	return c.length.Set(ctx, length)
+}
+
+// Has reports whether the value exists in the store or not.
+func (c ChunkedItem[V]) Has(ctx context.Context) (bool, error) {
+	return c.length.Has(ctx)
+}
+
+// Remove removes the value and all of its chunks from the store.
+// If the value does not exist then this is a no-op.
+func (c ChunkedItem[V]) Remove(ctx context.Context) error {
+	length, err := c.length.Get(ctx)
+	switch {
+	case err == nil:
+	case errors.Is(err, ErrNotFound):
+		return nil
+	default:
+		return err
+	}
+
+	for i := uint64(0); i < length; i++ {
+		err = c.chunks.Remove(ctx, i)
+		if err != nil {
+			return err
+		}
+	}
+	err = c.checksum.Remove(ctx)
+	if err != nil {
+		return err
+	}
+	return c.length.Remove(ctx)
+}
@@ -0,0 +1,110 @@
+package collections
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestChunkedItem(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	item := NewChunkedItem(schemaBuilder, NewPrefix(0), "chunked", StringValue, 4)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// get when empty
+	_, err = item.Get(ctx)
+	require.ErrorIs(t, err, ErrNotFound)
+	has, err := item.Has(ctx)
+	require.NoError(t, err)
+	require.False(t, has)
+
+	// set a value spanning multiple chunks
+	err = item.Set(ctx, "hello world")
+	require.NoError(t, err)
+	v, err := item.Get(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "hello world", v)
+	length, err := item.length.Get(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(3), length)
+
+	// set a shorter value removes the stale chunks
+	err = item.Set(ctx, "hi")
+	require.NoError(t, err)
+	v, err = item.Get(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "hi", v)
+	for i := uint64(1); i < 3; i++ {
+		has, err = item.chunks.Has(ctx, i)
+		require.NoError(t, err)
+		require.False(t, has)
+	}
+
+	// empty values are stored with no chunks
+	err = item.Set(ctx, "")
+	require.NoError(t, err)
+	v, err = item.Get(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "", v)
+
+	// tampered chunks are detected on read
+	err = item.Set(ctx, "hello world")
+	require.NoError(t, err)
+	err = item.chunks.Set(ctx, 1, []byte("XXXX"))
+	require.NoError(t, err)
+	_, err = item.Get(ctx)
+	require.ErrorIs(t, err, ErrChunkedItemCorrupted)
+
+	// missing chunks are detected on read
+	err = item.chunks.Remove(ctx, 2)
+	require.NoError(t, err)
+	_, err = item.Get(ctx)
+	require.ErrorIs(t, err, ErrChunkedItemCorrupted)
+
+	// a missing checksum is detected on read
+	err = item.Set(ctx, "hello world")
+	require.NoError(t, err)
+	err = item.checksum.Remove(ctx)
+	require.NoError(t, err)
+	has, err = item.Has(ctx)
+	require.NoError(t, err)
+	require.True(t, has)
+	_, err = item.Get(ctx)
+	require.ErrorIs(t, err, ErrChunkedItemCorrupted)
+	require.NotErrorIs(t, err, ErrNotFound)
+
+	// remove
+	err = item.Remove(ctx)
+	require.NoError(t, err)
+	_, err = item.Get(ctx)
+	require.ErrorIs(t, err, ErrNotFound)
+	has, err = item.chunks.Has(ctx, 0)
+	require.NoError(t, err)
+	require.False(t, has)
+
+	// invalid chunk size
+	require.Panics(t, func() {
+		NewChunkedItem(NewSchemaBuilder(sk), NewPrefix(1), "invalid", StringValue, 0)
+	})
+}
+
+func TestChunkedItem_StringPrefix(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	item := NewChunkedItem(schemaBuilder, NewPrefix("chunked"), "chunked", StringValue, 4)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// each sub collection keeps its own prefix
+	require.Equal(t, []byte("chunked\x00"), item.chunks.GetPrefix())
+	require.Equal(t, []byte("chunked\x01"), (Map[noKey, uint64])(item.length).GetPrefix())
+	require.Equal(t, []byte("chunked\x02"), (Map[noKey, []byte])(item.checksum).GetPrefix())
+
+	err = item.Set(ctx, "hello world")
+	require.NoError(t, err)
+	v, err := item.Get(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "hello world", v)
+}
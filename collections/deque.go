@@ -0,0 +1,254 @@
+package collections
+
+import (
+	"context"
+	"errors"
+
+	"cosmossdk.io/collections/codec"
+)
+
+// ErrEmptyQueue is returned when trying to pop or peek an element from an empty Queue or Deque.
+var ErrEmptyQueue = errors.New("queue is empty")
+
+const (
+	DequeElementsNameSuffix   = "_elements"
+	DequeHeadNameSuffix       = "_head"
+	DequeTailNameSuffix       = "_tail"
+	DequeElementsPrefixSuffix = 0x0
+	DequeHeadPrefixSuffix     = 0x1
+	DequeTailPrefixSuffix     = 0x2
+
+	// dequeStartIndex is the index at which an empty Deque starts, it sits in
+	// the middle of the uint64 domain so that the Deque can grow in both directions.
+	dequeStartIndex uint64 = 1 << 63
+)
+
+// NewDeque creates a new Deque instance. Since Deque relies on three collections,
+// one for the elements and two for the head and tail indexes, it will register
+// three state objects on the schema builder. The elements are a map whose prefix
+// is the provided prefix suffixed with DequeElementsPrefixSuffix, and whose name is
+// suffixed with DequeElementsNameSuffix. The head and tail are items, whose prefixes
+// are suffixed with DequeHeadPrefixSuffix and DequeTailPrefixSuffix and names
+// are suffixed with DequeHeadNameSuffix and DequeTailNameSuffix.
+func NewDeque[T any](sb *SchemaBuilder, prefix Prefix, name string, vc codec.ValueCodec[T]) Deque[T] {
+	return Deque[T]{
+		head:     NewItem(sb, append(prefix, DequeHeadPrefixSuffix), name+DequeHeadNameSuffix, Uint64Value),
+		tail:     NewItem(sb, append(prefix, DequeTailPrefixSuffix), name+DequeTailNameSuffix, Uint64Value),
+		elements: NewMap(sb, append(prefix, DequeElementsPrefixSuffix), name+DequeElementsNameSuffix, Uint64Key, vc),
+	}
+}
+
+// Deque is a double-ended queue sitting on top of a KVStore, elements can be
+// pushed and popped from both the front and the back.
+// It relies on a Map[uint64, T] for the elements, whose keys are ordered from
+// the front to the back, and on two Item[uint64] for the index of the front
+// element and the index following the back element.
+type Deque[T any] struct {
+	head     Item[uint64]
+	tail     Item[uint64]
+	elements Map[uint64, T]
+}
+
+// PushFront adds an element to the front of the Deque.
+func (d Deque[T]) PushFront(ctx context.Context, elem T) error {
+	head, _, err := d.bounds(ctx)
+	if err != nil {
+		return err
+	}
+	head--
+	err = d.elements.Set(ctx, head, elem)
+	if err != nil {
+		return err
+	}
+	return d.head.Set(ctx, head)
+}
+
+// PushBack adds an element to the back of the Deque.
+func (d Deque[T]) PushBack(ctx context.Context, elem T) error {
+	_, tail, err := d.bounds(ctx)
+	if err != nil {
+		return err
+	}
+	err = d.elements.Set(ctx, tail, elem)
+	if err != nil {
+		return err
+	}
+	return d.tail.Set(ctx, tail+1)
+}
+
+// PopFront removes the element at the front of the Deque and returns it.
+// Fails with ErrEmptyQueue if the Deque is empty.
+func (d Deque[T]) PopFront(ctx context.Context) (elem T, err error) {
+	head, tail, err := d.bounds(ctx)
+	if err != nil {
+		return elem, err
+	}
+	if head == tail {
+		return elem, ErrEmptyQueue
+	}
+	elem, err = d.elements.Get(ctx, head)
+	if err != nil {
+		return elem, err
+	}
+	err = d.elements.Remove(ctx, head)
+	if err != nil {
+		return elem, err
+	}
+	return elem, d.head.Set(ctx, head+1)
+}
+
+// PopBack removes the element at the back of the Deque and returns it.
+// Fails with ErrEmptyQueue if the Deque is empty.
+func (d Deque[T]) PopBack(ctx context.Context) (elem T, err error) {
+	head, tail, err := d.bounds(ctx)
+	if err != nil {
+		return elem, err
+	}
+	if head == tail {
+		return elem, ErrEmptyQueue
+	}
+	tail--
+	elem, err = d.elements.Get(ctx, tail)
+	if err != nil {
+		return elem, err
+	}
+	err = d.elements.Remove(ctx, tail)
+	if err != nil {
+		return elem, err
+	}
+	return elem, d.tail.Set(ctx, tail)
+}
+
+// PeekFront returns the element at the front of the Deque without removing it.
+// Fails with ErrEmptyQueue if the Deque is empty.
+func (d Deque[T]) PeekFront(ctx context.Context) (elem T, err error) {
+	head, tail, err := d.bounds(ctx)
+	if err != nil {
+		return elem, err
+	}
+	if head == tail {
+		return elem, ErrEmptyQueue
+	}
+	return d.elements.Get(ctx, head)
+}
+
+// PeekBack returns the element at the back of the Deque without removing it.
+// Fails with ErrEmptyQueue if the Deque is empty.
+func (d Deque[T]) PeekBack(ctx context.Context) (elem T, err error) {
+	head, tail, err := d.bounds(ctx)
+	if err != nil {
+		return elem, err
+	}
+	if head == tail {
+		return elem, ErrEmptyQueue
+	}
+	return d.elements.Get(ctx, tail-1)
+}
+
+// Len returns the number of elements in the Deque.
+func (d Deque[T]) Len(ctx context.Context) (uint64, error) {
+	head, tail, err := d.bounds(ctx)
+	if err != nil {
+		return 0, err
+	}
+	return tail - head, nil
+}
+
+// Iterate iterates over the Deque from the front to the back, or in the order
+// defined by the provided Ranger. The range and the keys of the returned Iterator
+// are positions, which are the distance of an element from the front.
+func (d Deque[T]) Iterate(ctx context.Context, rng Ranger[uint64]) (Iterator[uint64, T], error) {
+	head, _, err := d.bounds(ctx)
+	if err != nil {
+		return Iterator[uint64, T]{}, err
+	}
+	var indexRange Ranger[uint64]
+	if rng != nil {
+		indexRange = dequeIndexRange{positions: rng, head: head}
+	}
+	iter, err := d.elements.Iterate(ctx, indexRange)
+	if err != nil {
+		return Iterator[uint64, T]{}, err
+	}
+	iter.kc = dequePositionKeyCodec{KeyCodec: iter.kc, head: head}
+	return iter, nil
+}
+
+// Walk walks over the Deque from the front to the back, or in the order defined
+// by the provided Ranger. It calls the walkFn for each element, where position is
+// the distance of the element from the front. If the callback function returns
+// true then the walking is stopped.
+func (d Deque[T]) Walk(ctx context.Context, rng Ranger[uint64], walkFn func(position uint64, elem T) (stop bool, err error)) error {
+	iter, err := d.Iterate(ctx, rng)
+	if err != nil {
+		return err
+	}
+	defer iter.Close()
+
+	for ; iter.Valid(); iter.Next() {
+		kv, err := iter.KeyValue()
+		if err != nil {
+			return err
+		}
+		stop, err := walkFn(kv.Key, kv.Value)
+		if err != nil {
+			return err
+		}
+		if stop {
+			return nil
+		}
+	}
+	return nil
+}
+
+// bounds returns the index of the front element and the index following
+// the back element, which are equal when the Deque is empty.
+func (d Deque[T]) bounds(ctx context.Context) (head, tail uint64, err error) {
+	head, err = d.head.GetOrDefault(ctx, dequeStartIndex)
+	if err != nil {
+		return 0, 0, err
+	}
+	tail, err = d.tail.GetOrDefault(ctx, dequeStartIndex)
+	if err != nil {
+		return 0, 0, err
+	}
+	return head, tail, nil
+}
+
+// dequeIndexRange converts a Ranger over positions relative to the head of a
+// Deque into a Ranger over the indexes of its elements.
+type dequeIndexRange struct {
+	positions Ranger[uint64]
+	head      uint64
+}
+
+func (r dequeIndexRange) RangeValues() (start, end *RangeKey[uint64], order Order, err error) {
+	start, end, order, err = r.positions.RangeValues()
+	if err != nil {
+		return nil, nil, 0, err
+	}
+	return r.toIndex(start), r.toIndex(end), order, nil
+}
+
+func (r dequeIndexRange) toIndex(position *RangeKey[uint64]) *RangeKey[uint64] {
+	if position == nil {
+		return nil
+	}
+	return &RangeKey[uint64]{kind: position.kind, key: position.key + r.head}
+}
+
+// dequePositionKeyCodec encodes the positions relative to the head of a Deque
+// as the indexes of its elements.
+type dequePositionKeyCodec struct {
+	codec.KeyCodec[uint64]
+	head uint64
+}
+
+func (c dequePositionKeyCodec) Encode(buffer []byte, position uint64) (int, error) {
+	return c.KeyCodec.Encode(buffer, position+c.head)
+}
+
+func (c dequePositionKeyCodec) Decode(buffer []byte) (int, uint64, error) {
+	read, index, err := c.KeyCodec.Decode(buffer)
+	return read, index - c.head, err
+}
@@ -0,0 +1,116 @@
+package collections
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDeque(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	deque := NewDeque(schemaBuilder, NewPrefix(0), "deque", StringValue)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// length when empty
+	length, err := deque.Len(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(0), length)
+
+	// pop and peek when empty should error with an empty queue error
+	_, err = deque.PopFront(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+	_, err = deque.PopBack(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+	_, err = deque.PeekFront(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+	_, err = deque.PeekBack(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+
+	// push on both ends
+	require.NoError(t, deque.PushBack(ctx, "b"))
+	require.NoError(t, deque.PushFront(ctx, "a"))
+	require.NoError(t, deque.PushBack(ctx, "c"))
+
+	length, err = deque.Len(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(3), length)
+
+	// peek
+	v, err := deque.PeekFront(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "a", v)
+	v, err = deque.PeekBack(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "c", v)
+
+	// walk from front to back
+	var positions []uint64
+	var elems []string
+	err = deque.Walk(ctx, nil, func(position uint64, elem string) (bool, error) {
+		positions = append(positions, position)
+		elems = append(elems, elem)
+		return false, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []uint64{0, 1, 2}, positions)
+	require.Equal(t, []string{"a", "b", "c"}, elems)
+
+	// walk a range of positions
+	elems = nil
+	err = deque.Walk(ctx, new(Range[uint64]).StartInclusive(1).EndExclusive(3), func(_ uint64, elem string) (bool, error) {
+		elems = append(elems, elem)
+		return false, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []string{"b", "c"}, elems)
+
+	// iterate from the back to the front, keys are positions
+	iter, err := deque.Iterate(ctx, new(Range[uint64]).Descending())
+	require.NoError(t, err)
+	kvs, err := iter.KeyValues()
+	require.NoError(t, err)
+	require.Equal(t, []KeyValue[uint64, string]{{Key: 2, Value: "c"}, {Key: 1, Value: "b"}, {Key: 0, Value: "a"}}, kvs)
+
+	// pop on both ends
+	v, err = deque.PopFront(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "a", v)
+	v, err = deque.PopBack(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "c", v)
+	v, err = deque.PopBack(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "b", v)
+
+	_, err = deque.PopFront(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+}
+
+func TestDeque_StringPrefix(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	deque := NewDeque(schemaBuilder, NewPrefix("deque"), "deque", StringValue)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// each sub collection keeps its own prefix
+	require.Equal(t, []byte("deque\x00"), deque.elements.GetPrefix())
+	require.Equal(t, []byte("deque\x01"), (Map[noKey, uint64])(deque.head).GetPrefix())
+	require.Equal(t, []byte("deque\x02"), (Map[noKey, uint64])(deque.tail).GetPrefix())
+
+	require.NoError(t, deque.PushBack(ctx, "b"))
+	require.NoError(t, deque.PushFront(ctx, "a"))
+
+	length, err := deque.Len(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(2), length)
+
+	v, err := deque.PopFront(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "a", v)
+	v, err = deque.PopBack(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "b", v)
+}
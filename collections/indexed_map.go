@@ -156,6 +156,33 @@ func (m *IndexedMap[PrimaryKey, Value, Idx]) Remove(ctx context.Context, pk Prim
 	return m.m.Remove(ctx, pk)
 }
 
+// RemoveWhere applies the same semantics as Map.RemoveWhere. Every removed
+// value is also unreferenced from the indexes.
+func (m *IndexedMap[PrimaryKey, Value, Idx]) RemoveWhere(ctx context.Context, ranger Ranger[PrimaryKey], predicate func(key PrimaryKey, value Value) (remove bool, err error)) error {
+	var toRemove []PrimaryKey
+	err := m.m.Walk(ctx, ranger, func(key PrimaryKey, value Value) (bool, error) {
+		remove, err := predicate(key, value)
+		if err != nil {
+			return true, err
+		}
+		if remove {
+			toRemove = append(toRemove, key)
+		}
+		return false, nil
+	})
+	if err != nil {
+		return err
+	}
+
+	for _, pk := range toRemove {
+		err = m.Remove(ctx, pk)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
 // Walk applies the same semantics as Map.Walk.
 func (m *IndexedMap[PrimaryKey, Value, Idx]) Walk(ctx context.Context, ranger Ranger[PrimaryKey], walkFunc func(key PrimaryKey, value Value) (stop bool, err error)) error {
 	return m.m.Walk(ctx, ranger, walkFunc)
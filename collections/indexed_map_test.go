@@ -108,6 +108,40 @@ func TestIndexedMap(t *testing.T) {
 	require.Equal(t, company{"milan", 4}, v)
 }
 
+func TestIndexedMap_RemoveWhere(t *testing.T) {
+	ctx := testutil.Context()
+	sk := testutil.KVStoreService(ctx, "test")
+
+	schema := collections.NewSchemaBuilder(sk)
+
+	im := newTestIndexedMap(schema)
+
+	require.NoError(t, im.Set(ctx, "1", company{City: "milan", Vat: 0}))
+	require.NoError(t, im.Set(ctx, "2", company{City: "rome", Vat: 1}))
+	require.NoError(t, im.Set(ctx, "3", company{City: "milan", Vat: 2}))
+
+	// remove every company in milan
+	err := im.RemoveWhere(ctx, nil, func(_ string, value company) (bool, error) {
+		return value.City == "milan", nil
+	})
+	require.NoError(t, err)
+
+	iter, err := im.Iterate(ctx, nil)
+	require.NoError(t, err)
+	keys, err := iter.Keys()
+	require.NoError(t, err)
+	require.Equal(t, []string{"2"}, keys)
+
+	// indexes are unreferenced
+	_, err = im.Indexes.Vat.MatchExact(ctx, 0)
+	require.ErrorIs(t, err, collections.ErrNotFound)
+	_, err = im.Indexes.Vat.MatchExact(ctx, 2)
+	require.ErrorIs(t, err, collections.ErrNotFound)
+	pk, err := im.Indexes.Vat.MatchExact(ctx, 1)
+	require.NoError(t, err)
+	require.Equal(t, "2", pk)
+}
+
 type inferIndex struct {
 	City *indexes.Multi[string, string, company]
 	Vat  *indexes.Unique[uint64, string, company]
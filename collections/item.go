@@ -3,6 +3,7 @@ package collections
 import (
 	"bytes"
 	"context"
+	"errors"
 	"fmt"
 
 	"cosmossdk.io/collections/codec"
@@ -31,6 +32,20 @@ func (i Item[V]) Get(ctx context.Context) (V, error) {
 	return (Map[noKey, V])(i).Get(ctx, noKey{})
 }
 
+// GetOrDefault gets the item, if it is not set it returns the provided default value.
+// If value decoding fails then an ErrEncoding is returned.
+func (i Item[V]) GetOrDefault(ctx context.Context, def V) (V, error) {
+	v, err := i.Get(ctx)
+	switch {
+	case err == nil:
+		return v, nil
+	case errors.Is(err, ErrNotFound):
+		return def, nil
+	default:
+		return v, err
+	}
+}
+
 // Set sets the item in the store. If Value encoding fails then an ErrEncoding is returned.
 func (i Item[V]) Set(ctx context.Context, value V) error {
 	return (Map[noKey, V])(i).Set(ctx, noKey{}, value)
@@ -13,12 +13,22 @@ func TestItem(t *testing.T) {
 	_, err := schemaBuilder.Build()
 	require.NoError(t, err)
 
+	// get or default when not set
+	i, err := item.GetOrDefault(ctx, 5)
+	require.NoError(t, err)
+	require.Equal(t, uint64(5), i)
+
 	// set
 	err = item.Set(ctx, 1000)
 	require.NoError(t, err)
 
 	// get
-	i, err := item.Get(ctx)
+	i, err = item.Get(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(1000), i)
+
+	// get or default when set
+	i, err = item.GetOrDefault(ctx, 5)
 	require.NoError(t, err)
 	require.Equal(t, uint64(1000), i)
 
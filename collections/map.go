@@ -57,7 +57,7 @@ func NewMap[K, V any](
 		kc:               keyCodec,
 		vc:               valueCodec,
 		sa:               schemaBuilder.schema.storeAccessor,
-		prefix:           prefix.Bytes(),
+		prefix:           bytes.Clone(prefix.Bytes()),
 		name:             name,
 		isSecondaryIndex: o.isSecondaryIndex,
 	}
@@ -148,6 +148,9 @@ func (m Map[K, V]) Iterate(ctx context.Context, ranger Ranger[K]) (Iterator[K, V
 // walk function with the decoded key and value. If the callback function
 // returns true then the walking is stopped.
 // A nil ranger equals to walking over the entire key and value set.
+// NOTE: the collection must not be modified from within the walk function,
+// as the underlying iterator is still open. Use RemoveWhere to delete entries
+// while scanning the collection.
 func (m Map[K, V]) Walk(ctx context.Context, ranger Ranger[K], walkFunc func(key K, value V) (stop bool, err error)) error {
 	iter, err := m.Iterate(ctx, ranger)
 	if err != nil {
@@ -184,6 +187,37 @@ func (m Map[K, V]) Clear(ctx context.Context, ranger Ranger[K]) error {
 	return deleteDomain(m.sa(ctx), startBytes, endBytes)
 }
 
+// RemoveWhere removes every key within the provided range for which the predicate
+// returns true. A nil ranger equals to scanning the whole collection.
+// The matching keys are collected before any of them is removed, so the predicate
+// always observes the collection as it was before the call, and the store is never
+// modified while an iterator over it is open. This makes it safe to use
+// for sweeps over the same collection that is being scanned.
+func (m Map[K, V]) RemoveWhere(ctx context.Context, ranger Ranger[K], predicate func(key K, value V) (remove bool, err error)) error {
+	var toRemove []K
+	err := m.Walk(ctx, ranger, func(key K, value V) (bool, error) {
+		remove, err := predicate(key, value)
+		if err != nil {
+			return true, err
+		}
+		if remove {
+			toRemove = append(toRemove, key)
+		}
+		return false, nil
+	})
+	if err != nil {
+		return err
+	}
+
+	for _, key := range toRemove {
+		err = m.Remove(ctx, key)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
 const clearBatchSize = 10000
 
 // deleteDomain deletes the domain of an iterator, the key difference
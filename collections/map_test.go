@@ -74,6 +74,42 @@ func TestMap_Clear(t *testing.T) {
 	})
 }
 
+func TestMap_RemoveWhere(t *testing.T) {
+	sk, ctx := deps()
+	m := NewMap(NewSchemaBuilder(sk), NewPrefix(0), "test", Uint64Key, Uint64Value)
+	for i := uint64(0); i < 10; i++ {
+		require.NoError(t, m.Set(ctx, i, i*10))
+	}
+
+	// remove the even values within the range, the predicate must observe
+	// every key in range even if previous keys were marked for removal
+	var seen []uint64
+	err := m.RemoveWhere(ctx, new(Range[uint64]).StartInclusive(2).EndExclusive(8), func(key, value uint64) (bool, error) {
+		seen = append(seen, key)
+		return value%20 == 0, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []uint64{2, 3, 4, 5, 6, 7}, seen)
+
+	iter, err := m.Iterate(ctx, nil)
+	require.NoError(t, err)
+	keys, err := iter.Keys()
+	require.NoError(t, err)
+	require.Equal(t, []uint64{0, 1, 3, 5, 7, 8, 9}, keys)
+
+	// an error in the predicate aborts without removing anything
+	err = m.RemoveWhere(ctx, nil, func(key, _ uint64) (bool, error) {
+		if key == 5 {
+			return false, errors.New("fail")
+		}
+		return true, nil
+	})
+	require.ErrorContains(t, err, "fail")
+	has, err := m.Has(ctx, 0)
+	require.NoError(t, err)
+	require.True(t, has)
+}
+
 func TestMap_IterateRaw(t *testing.T) {
 	sk, ctx := deps()
 	// safety check to ensure prefix boundaries are not crossed
@@ -0,0 +1,51 @@
+package collections
+
+import (
+	"context"
+
+	"cosmossdk.io/collections/codec"
+)
+
+// Queue builds on top of a Deque, and represents a FIFO queue where elements
+// are pushed to the back and popped from the front.
+type Queue[T any] Deque[T]
+
+// NewQueue creates a new Queue instance. It registers the same state objects
+// a Deque does, see NewDeque.
+func NewQueue[T any](sb *SchemaBuilder, prefix Prefix, name string, vc codec.ValueCodec[T]) Queue[T] {
+	return (Queue[T])(NewDeque(sb, prefix, name, vc))
+}
+
+// Push adds an element to the back of the Queue.
+func (q Queue[T]) Push(ctx context.Context, elem T) error {
+	return (Deque[T])(q).PushBack(ctx, elem)
+}
+
+// Pop removes the element at the front of the Queue and returns it.
+// Fails with ErrEmptyQueue if the Queue is empty.
+func (q Queue[T]) Pop(ctx context.Context) (T, error) {
+	return (Deque[T])(q).PopFront(ctx)
+}
+
+// Peek returns the element at the front of the Queue without removing it.
+// Fails with ErrEmptyQueue if the Queue is empty.
+func (q Queue[T]) Peek(ctx context.Context) (T, error) {
+	return (Deque[T])(q).PeekFront(ctx)
+}
+
+// Len returns the number of elements in the Queue.
+func (q Queue[T]) Len(ctx context.Context) (uint64, error) {
+	return (Deque[T])(q).Len(ctx)
+}
+
+// Iterate iterates over the Queue from the front to the back, following the
+// same semantics as Deque.Iterate.
+func (q Queue[T]) Iterate(ctx context.Context, rng Ranger[uint64]) (Iterator[uint64, T], error) {
+	return (Deque[T])(q).Iterate(ctx, rng)
+}
+
+// Walk walks over the Queue from the front to the back, following the same
+// semantics as Deque.Walk.
+func (q Queue[T]) Walk(ctx context.Context, rng Ranger[uint64], walkFn func(position uint64, elem T) (stop bool, err error)) error {
+	return (Deque[T])(q).Walk(ctx, rng, walkFn)
+}
@@ -0,0 +1,58 @@
+package collections
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestQueue(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	queue := NewQueue(schemaBuilder, NewPrefix(0), "queue", StringValue)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// pop and peek when empty should error with an empty queue error
+	_, err = queue.Pop(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+	_, err = queue.Peek(ctx)
+	require.ErrorIs(t, err, ErrEmptyQueue)
+
+	// push
+	require.NoError(t, queue.Push(ctx, "foo"))
+	require.NoError(t, queue.Push(ctx, "bar"))
+
+	length, err := queue.Len(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(2), length)
+
+	// elements are popped in insertion order
+	v, err := queue.Peek(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "foo", v)
+	v, err = queue.Pop(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "foo", v)
+
+	require.NoError(t, queue.Push(ctx, "baz"))
+
+	var elems []string
+	err = queue.Walk(ctx, nil, func(_ uint64, elem string) (bool, error) {
+		elems = append(elems, elem)
+		return false, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []string{"bar", "baz"}, elems)
+
+	v, err = queue.Pop(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "bar", v)
+	v, err = queue.Pop(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "baz", v)
+
+	length, err = queue.Len(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(0), length)
+}
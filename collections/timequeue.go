@@ -0,0 +1,163 @@
+package collections
+
+import (
+	"context"
+	"errors"
+
+	"cosmossdk.io/collections/codec"
+)
+
+// NewTimeQueue creates a new TimeQueue instance. The first KeyCodec encodes the
+// priority of each entry, which is usually a time.Time or a block height, and must
+// retain ordering. The second KeyCodec encodes the caller supplied id which
+// disambiguates entries sharing the same priority.
+// The queue is stored as a Map[Pair[K, ID], V] under the provided prefix, which is
+// the same layout used by existing Pair(time, id) keyed queues, such as gov's
+// proposal queues, allowing them to adopt a TimeQueue without a state migration.
+func NewTimeQueue[K, ID, V any](
+	sb *SchemaBuilder,
+	prefix Prefix,
+	name string,
+	kc codec.KeyCodec[K],
+	idc codec.KeyCodec[ID],
+	vc codec.ValueCodec[V],
+) TimeQueue[K, ID, V] {
+	return TimeQueue[K, ID, V]{
+		entries: NewMap(sb, prefix, name, PairKeyCodec(kc, idc), vc),
+	}
+}
+
+// TimeQueue is a priority queue whose entries are ordered by a key K, such as a
+// timestamp or a block height, and then by an id ID. It is meant to back
+// deadline driven workflows like voting periods, unbonding and timeouts, where
+// every entry that is due at a given point is processed at once.
+// It relies on a Map[Pair[K, ID], V] for the entries.
+type TimeQueue[K, ID, V any] struct {
+	entries Map[Pair[K, ID], V]
+}
+
+// Insert adds the value to the queue at the provided priority and id. If an entry
+// with the same priority and id already exists, its value is overwritten.
+func (q TimeQueue[K, ID, V]) Insert(ctx context.Context, at K, id ID, value V) error {
+	return q.entries.Set(ctx, Join(at, id), value)
+}
+
+// Get returns the value of the entry identified by the provided priority and id.
+// Errors with ErrNotFound if the entry does not exist.
+func (q TimeQueue[K, ID, V]) Get(ctx context.Context, at K, id ID) (V, error) {
+	return q.entries.Get(ctx, Join(at, id))
+}
+
+// Remove removes the entry identified by the provided priority and id.
+// If the entry does not exist then this is a no-op.
+func (q TimeQueue[K, ID, V]) Remove(ctx context.Context, at K, id ID) error {
+	return q.entries.Remove(ctx, Join(at, id))
+}
+
+// WalkDue calls the walkFn for each entry whose priority is lower than or equal
+// to the provided one, in ascending priority and id order. The entries are
+// not removed from the queue. If the callback function returns true then the
+// walking is stopped.
+func (q TimeQueue[K, ID, V]) WalkDue(ctx context.Context, until K, walkFn func(at K, id ID, value V) (stop bool, err error)) error {
+	return q.entries.Walk(ctx, NewPrefixUntilPairRange[K, ID](until), func(key Pair[K, ID], value V) (bool, error) {
+		return walkFn(key.K1(), key.K2(), value)
+	})
+}
+
+// popDueBatchSize is the maximum number of due keys PopDue reads from the store
+// before closing the iterator and popping them.
+const popDueBatchSize = 100
+
+// PopDue removes every entry whose priority is lower than or equal to the provided
+// one and calls the popFn for each of them, in ascending priority and id order.
+// The due keys are read in batches of at most popDueBatchSize. Each batch is read
+// and the iterator closed before any of its entries is removed. Values are only
+// loaded right before being popped. This bounds the memory used and the reads
+// done by a call that stops early. Each entry is removed right before popFn is
+// called with it, so popFn is free to modify the queue, including re-inserting
+// the entry being popped, which is not visited again in the same call.
+// Entries removed by popFn before being reached are skipped. Whether due entries
+// that popFn inserts after the entry being popped are visited in the same call
+// depends on the batch boundaries, so popFn should not rely on either behavior.
+// If popFn returns true then popping is stopped and the remaining due entries are
+// left in the queue. If popFn returns an error then the error is returned, the
+// entries popped so far, including the one popFn failed on, stay removed and the
+// remaining due entries are left in the queue; callers are expected to discard
+// the state changes of the failing operation, as a failing transaction does.
+func (q TimeQueue[K, ID, V]) PopDue(ctx context.Context, until K, popFn func(at K, id ID, value V) (stop bool, err error)) error {
+	rng := NewPrefixUntilPairRange[K, ID](until)
+	for {
+		keys, err := q.keys(ctx, rng, popDueBatchSize)
+		if err != nil {
+			return err
+		}
+
+		for _, key := range keys {
+			var value V
+			value, err = q.entries.Get(ctx, key)
+			switch {
+			case err == nil:
+			case errors.Is(err, ErrNotFound):
+				// removed by a previous popFn call
+				continue
+			default:
+				return err
+			}
+
+			err = q.entries.Remove(ctx, key)
+			if err != nil {
+				return err
+			}
+			var stop bool
+			stop, err = popFn(key.K1(), key.K2(), value)
+			if err != nil {
+				return err
+			}
+			if stop {
+				return nil
+			}
+		}
+
+		if len(keys) < popDueBatchSize {
+			return nil
+		}
+		// continue right after the last visited key.
+		rng = &PairRange[K, ID]{start: RangeKeyNext(keys[len(keys)-1]), end: rng.end}
+	}
+}
+
+// keys returns at most limit keys within the provided range, closing the
+// iterator before returning.
+func (q TimeQueue[K, ID, V]) keys(ctx context.Context, rng Ranger[Pair[K, ID]], limit int) ([]Pair[K, ID], error) {
+	iter, err := q.entries.Iterate(ctx, rng)
+	if err != nil {
+		return nil, err
+	}
+	defer iter.Close()
+
+	keys := make([]Pair[K, ID], 0, limit)
+	for ; iter.Valid() && len(keys) < limit; iter.Next() {
+		key, err := iter.Key()
+		if err != nil {
+			return nil, err
+		}
+		keys = append(keys, key)
+	}
+	return keys, nil
+}
+
+// IsEmpty reports whether the queue contains no entries.
+func (q TimeQueue[K, ID, V]) IsEmpty(ctx context.Context) (bool, error) {
+	iter, err := q.entries.Iterate(ctx, nil)
+	if err != nil {
+		return false, err
+	}
+	defer iter.Close()
+	return !iter.Valid(), nil
+}
+
+// Iterate provides an Iterator over all the entries of the queue, in ascending
+// priority and id order, or in the order defined by the provided Ranger.
+func (q TimeQueue[K, ID, V]) Iterate(ctx context.Context, ranger Ranger[Pair[K, ID]]) (Iterator[Pair[K, ID], V], error) {
+	return q.entries.Iterate(ctx, ranger)
+}
@@ -0,0 +1,177 @@
+package collections
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+
+	"cosmossdk.io/collections/codec"
+)
+
+func TestTimeQueue(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	queue := NewTimeQueue(schemaBuilder, NewPrefix("queue"), "queue", Uint64Key, Uint64Key, StringValue)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// empty when nothing was inserted
+	empty, err := queue.IsEmpty(ctx)
+	require.NoError(t, err)
+	require.True(t, empty)
+
+	// insert entries, two of them sharing the same height
+	require.NoError(t, queue.Insert(ctx, 10, 1, "a"))
+	require.NoError(t, queue.Insert(ctx, 5, 2, "b"))
+	require.NoError(t, queue.Insert(ctx, 10, 3, "c"))
+	require.NoError(t, queue.Insert(ctx, 20, 4, "d"))
+
+	empty, err = queue.IsEmpty(ctx)
+	require.NoError(t, err)
+	require.False(t, empty)
+
+	v, err := queue.Get(ctx, 10, 1)
+	require.NoError(t, err)
+	require.Equal(t, "a", v)
+
+	// walking due entries does not remove them
+	var walked []string
+	err = queue.WalkDue(ctx, 10, func(_, _ uint64, value string) (bool, error) {
+		walked = append(walked, value)
+		return false, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []string{"b", "a", "c"}, walked)
+
+	// remove an entry before it becomes due
+	err = queue.Remove(ctx, 20, 4)
+	require.NoError(t, err)
+	_, err = queue.Get(ctx, 20, 4)
+	require.ErrorIs(t, err, ErrNotFound)
+
+	// pop with stop leaves the remaining due entries in the queue
+	var popped []string
+	err = queue.PopDue(ctx, 10, func(_, _ uint64, value string) (bool, error) {
+		popped = append(popped, value)
+		return true, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []string{"b"}, popped)
+
+	// popping can re-insert entries, including due ones, which are not
+	// visited in the same pass
+	popped = nil
+	err = queue.PopDue(ctx, 10, func(at, id uint64, value string) (bool, error) {
+		popped = append(popped, value)
+		return false, queue.Insert(ctx, at, id, value+"'")
+	})
+	require.NoError(t, err)
+	require.Equal(t, []string{"a", "c"}, popped)
+
+	iter, err := queue.Iterate(ctx, nil)
+	require.NoError(t, err)
+	values, err := iter.Values()
+	require.NoError(t, err)
+	require.Equal(t, []string{"a'", "c'"}, values)
+
+	// nothing is due before the lowest height
+	err = queue.PopDue(ctx, 9, func(_, _ uint64, _ string) (bool, error) {
+		t.Fatal("no entry should be due")
+		return false, nil
+	})
+	require.NoError(t, err)
+
+	// an error leaves the entries following the failing one in the queue
+	err = queue.PopDue(ctx, 10, func(_, _ uint64, _ string) (bool, error) {
+		return false, errors.New("fail")
+	})
+	require.ErrorContains(t, err, "fail")
+	_, err = queue.Get(ctx, 10, 1)
+	require.ErrorIs(t, err, ErrNotFound)
+	v, err = queue.Get(ctx, 10, 3)
+	require.NoError(t, err)
+	require.Equal(t, "c'", v)
+
+	// pop everything
+	err = queue.PopDue(ctx, 10, func(_, _ uint64, _ string) (bool, error) { return false, nil })
+	require.NoError(t, err)
+	empty, err = queue.IsEmpty(ctx)
+	require.NoError(t, err)
+	require.True(t, empty)
+}
+
+func TestTimeQueue_PairMapLayout(t *testing.T) {
+	sk, ctx := deps()
+
+	// a queue stored as a plain Pair keyed map, like gov's proposal queues
+	m := NewMap(NewSchemaBuilder(sk), NewPrefix("queue"), "queue", PairKeyCodec(Uint64Key, Uint64Key), Uint64Value)
+	require.NoError(t, m.Set(ctx, Join[uint64, uint64](10, 1), 1))
+	require.NoError(t, m.Set(ctx, Join[uint64, uint64](20, 2), 2))
+
+	// a TimeQueue over the same prefix reads the existing entries as is
+	queue := NewTimeQueue(NewSchemaBuilder(sk), NewPrefix("queue"), "queue", Uint64Key, Uint64Key, Uint64Value)
+	var popped []uint64
+	err := queue.PopDue(ctx, 15, func(_, id, value uint64) (bool, error) {
+		require.Equal(t, id, value)
+		popped = append(popped, id)
+		return false, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, []uint64{1}, popped)
+
+	has, err := m.Has(ctx, Join[uint64, uint64](10, 1))
+	require.NoError(t, err)
+	require.False(t, has)
+	has, err = m.Has(ctx, Join[uint64, uint64](20, 2))
+	require.NoError(t, err)
+	require.True(t, has)
+}
+
+// decodeCountingValueCodec counts the values decoded by the wrapped ValueCodec.
+type decodeCountingValueCodec[V any] struct {
+	codec.ValueCodec[V]
+	decoded *int
+}
+
+func (c decodeCountingValueCodec[V]) Decode(b []byte) (V, error) {
+	*c.decoded++
+	return c.ValueCodec.Decode(b)
+}
+
+func TestTimeQueue_PopDueBatches(t *testing.T) {
+	sk, ctx := deps()
+	decoded := 0
+	vc := decodeCountingValueCodec[uint64]{ValueCodec: Uint64Value, decoded: &decoded}
+	queue := NewTimeQueue(NewSchemaBuilder(sk), NewPrefix("queue"), "queue", Uint64Key, Uint64Key, codec.ValueCodec[uint64](vc))
+
+	const entries = popDueBatchSize*2 + popDueBatchSize/2
+	for i := uint64(0); i < entries; i++ {
+		require.NoError(t, queue.Insert(ctx, i, i, i))
+	}
+
+	// stopping after the first entry does not load any other value
+	decoded = 0
+	err := queue.PopDue(ctx, entries, func(_, _, value uint64) (bool, error) {
+		require.Equal(t, uint64(0), value)
+		return true, nil
+	})
+	require.NoError(t, err)
+	require.Equal(t, 1, decoded)
+
+	// popping everything crosses the batch boundaries in order
+	var popped []uint64
+	err = queue.PopDue(ctx, entries, func(_, _, value uint64) (bool, error) {
+		popped = append(popped, value)
+		return false, nil
+	})
+	require.NoError(t, err)
+	require.Len(t, popped, entries-1)
+	for i, value := range popped {
+		require.Equal(t, uint64(i+1), value)
+	}
+
+	empty, err := queue.IsEmpty(ctx)
+	require.NoError(t, err)
+	require.True(t, empty)
+}
@@ -61,3 +61,26 @@ func TestVec(t *testing.T) {
 	require.NoError(t, err)
 	require.Equal(t, "bar", v)
 }
+
+func TestVec_StringPrefix(t *testing.T) {
+	sk, ctx := deps()
+	schemaBuilder := NewSchemaBuilder(sk)
+	vec := NewVec(schemaBuilder, NewPrefix("vec"), "vec", StringValue)
+	_, err := schemaBuilder.Build()
+	require.NoError(t, err)
+
+	// each sub collection keeps its own prefix
+	require.Equal(t, []byte("vec\x00"), vec.elements.GetPrefix())
+	require.Equal(t, []byte("vec\x01"), (Map[noKey, uint64])(vec.length).GetPrefix())
+
+	require.NoError(t, vec.Push(ctx, "foo"))
+	require.NoError(t, vec.Push(ctx, "bar"))
+
+	length, err := vec.Len(ctx)
+	require.NoError(t, err)
+	require.Equal(t, uint64(2), length)
+
+	v, err := vec.Pop(ctx)
+	require.NoError(t, err)
+	require.Equal(t, "bar", v)
+}